@@ -29,31 +29,146 @@ pub enum SnowyInstruction {
     /// 2. [] System program
     /// 3. [] Instructions sysvar
     Record { request_hash: [u8; 32], timestamp: i64 },
+
+    /// Overwrites the `request_hash`/`timestamp` of an existing record.
+    ///
+    /// Security model: the stored `authority` must sign, and the transaction must
+    /// include a fresh Ed25519SigVerify instruction over the new 32-byte `request_hash`.
+    ///
+    /// Accounts:
+    /// 0. [signer] Record authority
+    /// 1. [writable] Record PDA account
+    /// 2. [] Instructions sysvar
+    Update { new_request_hash: [u8; 32], timestamp: i64 },
+
+    /// Closes a record, transferring its lamports to `destination` and zeroing its data.
+    ///
+    /// Accounts:
+    /// 0. [signer] Record authority
+    /// 1. [writable] Record PDA account
+    /// 2. [writable] Destination account for reclaimed lamports
+    CloseAccount,
+
+    /// Rotates the authority allowed to update or close a record.
+    ///
+    /// Accounts:
+    /// 0. [signer] Current record authority
+    /// 1. [writable] Record PDA account
+    SetAuthority { new_authority: Pubkey },
+
+    /// Records an inference authorization that requires a quorum of signers.
+    ///
+    /// Security model: the transaction must include Ed25519SigVerify instructions
+    /// covering at least `threshold` distinct pubkeys from `signers`, each over the
+    /// 32-byte `request_hash` message. `signers` must not contain duplicates. The
+    /// resulting record has no mutable authority, so it can only ever be attested
+    /// once, not later updated or closed by a single key.
+    ///
+    /// Accounts:
+    /// 0. [signer] Payer (funds the record PDA; need not be one of `signers`)
+    /// 1. [writable] Record PDA account, derived from a hash of the sorted signer set
+    /// 2. [] System program
+    /// 3. [] Instructions sysvar
+    RecordMultisig {
+        request_hash: [u8; 32],
+        timestamp: i64,
+        threshold: u8,
+        signers: Vec<Pubkey>,
+    },
+
+    /// Pre-allocates an oversized PDA that large metadata can be streamed into via
+    /// repeated `Write` instructions, bypassing the single-transaction size/compute cap.
+    ///
+    /// Accounts:
+    /// 0. [signer] Signer (wallet), becomes the record's authority
+    /// 1. [writable] Record PDA account (created by this instruction)
+    /// 2. [] System program
+    InitializeLarge { space: u64 },
+
+    /// Copies `data` into the record PDA's account data starting at `offset`, after
+    /// verifying the record's authority signed. Used to stream a payload too large
+    /// for a single transaction across multiple `Write` calls.
+    ///
+    /// Accounts:
+    /// 0. [signer] Record authority
+    /// 1. [writable] Record PDA account (must have been created via `InitializeLarge`)
+    Write { offset: u64, data: Vec<u8> },
+
+    /// Records an inference authorization exactly as `Record` does, then CPIs into
+    /// the Wormhole core bridge so guardians observe and attest it for consumption
+    /// on other chains. Requires the `wormhole` feature.
+    ///
+    /// Accounts:
+    /// 0. [signer] Signer (wallet)
+    /// 1. [writable] Record PDA account (created by this instruction)
+    /// 2. [] System program
+    /// 3. [] Instructions sysvar
+    /// 4. [] Wormhole core bridge program
+    /// 5. [writable] Wormhole bridge config account
+    /// 6. [writable] Wormhole message account (fresh keypair, owned by system program)
+    /// 7. [writable] Wormhole fee collector account
+    /// 8. [writable] Wormhole sequence account for this emitter
+    /// 9. [] Clock sysvar
+    #[cfg(feature = "wormhole")]
+    RecordAndBridge {
+        request_hash: [u8; 32],
+        timestamp: i64,
+        nonce: u32,
+    },
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct InferenceRecord {
     pub signer: Pubkey,
+    /// The key allowed to `Update`/`CloseAccount`/`SetAuthority` this record. For a
+    /// `RecordMultisig` record this is set to the record's own PDA, which can never
+    /// sign a later instruction, making the record immutable once created (a second
+    /// `RecordMultisig` under the same signer set hits the same PDA and fails with
+    /// `AlreadyInitialized`).
+    pub authority: Pubkey,
     pub request_hash: [u8; 32],
     pub timestamp: i64,
     pub bump: u8,
+    /// Number of distinct `signers` entries whose ed25519 verification was required
+    /// to create this record.
+    pub threshold: u8,
+    /// Sorted, deduplicated set of pubkeys allowed to attest to this record.
+    /// A single-signer `Record` stores just `[signer]` here with `threshold: 1`.
+    pub signers: Vec<Pubkey>,
 }
 
+// Discriminants are part of the on-chain error ABI: append new variants at the
+// end and never renumber existing ones, or deployed clients will misdecode
+// `ProgramError::Custom` codes from older transactions.
 #[derive(thiserror::Error, Debug, Clone)]
+#[repr(u32)]
 pub enum SnowyError {
     #[error("Invalid instruction data")]
-    InvalidInstructionData,
+    InvalidInstructionData = 0,
     #[error("Missing required ed25519 verification instruction")]
-    MissingEd25519Verification,
+    MissingEd25519Verification = 1,
     #[error("Record PDA mismatch")]
-    RecordPdaMismatch,
+    RecordPdaMismatch = 2,
     #[error("Record already initialized")]
-    AlreadyInitialized,
+    AlreadyInitialized = 3,
+    #[error("Signer is not the record authority")]
+    Unauthorized = 4,
+    #[error("Multisig threshold must be nonzero and not exceed the number of signers")]
+    InvalidThreshold = 5,
+    #[error("Multisig signer set contains a duplicate pubkey")]
+    DuplicateSigner = 6,
+    #[error("Requested space is too small to hold the record header")]
+    SpaceTooSmall = 7,
+    #[error("Write would touch the header or fall outside the allocated space")]
+    WriteOutOfBounds = 8,
+    #[cfg(feature = "wormhole")]
+    #[error("Provided account is not the Wormhole core bridge program")]
+    IncorrectBridgeProgram = 9,
 }
 
 impl From<SnowyError> for ProgramError {
-    fn from(_: SnowyError) -> Self {
-        ProgramError::InvalidArgument
+    fn from(e: SnowyError) -> Self {
+        ProgramError::Custom(e as u32)
     }
 }
 
@@ -65,6 +180,28 @@ pub fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data:
             request_hash,
             timestamp,
         } => record(program_id, accounts, request_hash, timestamp),
+        SnowyInstruction::Update {
+            new_request_hash,
+            timestamp,
+        } => update(program_id, accounts, new_request_hash, timestamp),
+        SnowyInstruction::CloseAccount => close_account(program_id, accounts),
+        SnowyInstruction::SetAuthority { new_authority } => {
+            set_authority(program_id, accounts, new_authority)
+        }
+        SnowyInstruction::RecordMultisig {
+            request_hash,
+            timestamp,
+            threshold,
+            signers,
+        } => record_multisig(program_id, accounts, request_hash, timestamp, threshold, signers),
+        SnowyInstruction::InitializeLarge { space } => initialize_large(program_id, accounts, space),
+        SnowyInstruction::Write { offset, data } => write(program_id, accounts, offset, data),
+        #[cfg(feature = "wormhole")]
+        SnowyInstruction::RecordAndBridge {
+            request_hash,
+            timestamp,
+            nonce,
+        } => record_and_bridge(program_id, accounts, request_hash, timestamp, nonce),
     }
 }
 
@@ -114,9 +251,12 @@ fn record(
 
     let record = InferenceRecord {
         signer: *signer.key,
+        authority: *signer.key,
         request_hash,
         timestamp,
         bump,
+        threshold: 1,
+        signers: vec![*signer.key],
     };
 
     let rent = Rent::get()?;
@@ -144,11 +284,499 @@ fn record(
     Ok(())
 }
 
+fn record_multisig(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    request_hash: [u8; 32],
+    timestamp: i64,
+    threshold: u8,
+    signers: Vec<Pubkey>,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let payer = next_account_info(account_info_iter)?;
+    let record_pda = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+    let instructions_sysvar = next_account_info(account_info_iter)?;
+
+    if !payer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if *system_program.key != solana_program::system_program::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if *instructions_sysvar.key != ix_sysvar::id() {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if threshold == 0 || threshold as usize > signers.len() {
+        return Err(SnowyError::InvalidThreshold.into());
+    }
+
+    let mut sorted_signers = signers.clone();
+    sorted_signers.sort();
+    sorted_signers.dedup();
+    if sorted_signers.len() != signers.len() {
+        return Err(SnowyError::DuplicateSigner.into());
+    }
+
+    // Derive the PDA from a hash of the sorted signer set so the address is
+    // deterministic regardless of the order callers supply `signers` in.
+    let signer_set_hash = solana_program::hash::hashv(
+        &sorted_signers.iter().map(Pubkey::as_ref).collect::<Vec<_>>(),
+    );
+
+    let (expected_pda, bump) = Pubkey::find_program_address(
+        &[b"snowy-multisig", signer_set_hash.as_ref()],
+        program_id,
+    );
+    if expected_pda != *record_pda.key {
+        msg!("Expected PDA: {}", expected_pda);
+        msg!("Provided PDA: {}", record_pda.key);
+        return Err(SnowyError::RecordPdaMismatch.into());
+    }
+
+    if record_pda.owner == program_id && !record_pda.data_is_empty() {
+        return Err(SnowyError::AlreadyInitialized.into());
+    }
+
+    let matched = count_ed25519_verifications(instructions_sysvar, &sorted_signers, &request_hash)?;
+    if matched < threshold as usize {
+        msg!("Only {} of required {} signers verified", matched, threshold);
+        return Err(SnowyError::MissingEd25519Verification.into());
+    }
+
+    // `authority` gates single-signer `Update`/`CloseAccount`/`SetAuthority`, which
+    // don't re-check `threshold`/`signers`. A multisig record's quorum only ever
+    // held at creation, so rather than let `payer` (who need not even be one of
+    // `signers`) become its sole unilateral owner, point `authority` at the record
+    // PDA itself: a PDA has no private key, so it can never appear as a signer on
+    // a later instruction and those single-authority paths become permanently
+    // unreachable for this record.
+    let record = InferenceRecord {
+        signer: *payer.key,
+        authority: *record_pda.key,
+        request_hash,
+        timestamp,
+        bump,
+        threshold,
+        signers: sorted_signers,
+    };
+
+    let rent = Rent::get()?;
+    let space = record.try_to_vec().map_err(|_| ProgramError::InvalidAccountData)?.len();
+    let lamports = rent.minimum_balance(space);
+
+    invoke_signed(
+        &system_instruction::create_account(
+            payer.key,
+            record_pda.key,
+            lamports,
+            space as u64,
+            program_id,
+        ),
+        &[payer.clone(), record_pda.clone(), system_program.clone()],
+        &[&[b"snowy-multisig", signer_set_hash.as_ref(), &[bump]]],
+    )?;
+
+    record
+        .serialize(&mut &mut record_pda.data.borrow_mut()[..])
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    msg!(
+        "SNOWY multisig record stored. threshold={}/{}, ts={} request_hash={:?}",
+        record.threshold,
+        record.signers.len(),
+        timestamp,
+        request_hash
+    );
+    Ok(())
+}
+
+/// Number of bytes reserved at the front of an `InitializeLarge` account to hold its
+/// authority. `Write` may not touch this range, so a payload can't clobber the pubkey
+/// that gates future writes.
+const LARGE_RECORD_HEADER_LEN: usize = 32;
+
+fn initialize_large(program_id: &Pubkey, accounts: &[AccountInfo], space: u64) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer = next_account_info(account_info_iter)?;
+    let record_pda = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+
+    if !signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if *system_program.key != solana_program::system_program::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if (space as usize) < LARGE_RECORD_HEADER_LEN {
+        return Err(SnowyError::SpaceTooSmall.into());
+    }
+
+    let (expected_pda, bump) = Pubkey::find_program_address(
+        &[b"snowy-large", signer.key.as_ref()],
+        program_id,
+    );
+    if expected_pda != *record_pda.key {
+        msg!("Expected PDA: {}", expected_pda);
+        msg!("Provided PDA: {}", record_pda.key);
+        return Err(SnowyError::RecordPdaMismatch.into());
+    }
+    if record_pda.owner == program_id && !record_pda.data_is_empty() {
+        return Err(SnowyError::AlreadyInitialized.into());
+    }
+
+    let rent = Rent::get()?;
+    let lamports = rent.minimum_balance(space as usize);
+
+    invoke_signed(
+        &system_instruction::create_account(
+            signer.key,
+            record_pda.key,
+            lamports,
+            space,
+            program_id,
+        ),
+        &[signer.clone(), record_pda.clone(), system_program.clone()],
+        &[&[b"snowy-large", signer.key.as_ref(), &[bump]]],
+    )?;
+
+    record_pda.data.borrow_mut()[..LARGE_RECORD_HEADER_LEN].copy_from_slice(signer.key.as_ref());
+
+    msg!("SNOWY large record initialized. authority={}, space={}", signer.key, space);
+    Ok(())
+}
+
+fn write(program_id: &Pubkey, accounts: &[AccountInfo], offset: u64, data: Vec<u8>) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let authority = next_account_info(account_info_iter)?;
+    let record_pda = next_account_info(account_info_iter)?;
+
+    if !authority.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if record_pda.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Re-derive the PDA from the `InitializeLarge` seeds instead of trusting the
+    // account's header bytes alone: a `Record`/`RecordMultisig` account also has
+    // the creator's pubkey at offset 0, so without this check its original
+    // creator could pass it off as a large record and `Write` straight over
+    // `request_hash`/`timestamp`/`authority`, bypassing `Update`'s ed25519
+    // re-verification and `SetAuthority`'s rotation entirely.
+    let (expected_pda, _bump) =
+        Pubkey::find_program_address(&[b"snowy-large", authority.key.as_ref()], program_id);
+    if expected_pda != *record_pda.key {
+        return Err(SnowyError::RecordPdaMismatch.into());
+    }
+
+    let offset = offset as usize;
+    if offset < LARGE_RECORD_HEADER_LEN {
+        return Err(SnowyError::WriteOutOfBounds.into());
+    }
+    let end = offset.checked_add(data.len()).ok_or(ProgramError::InvalidArgument)?;
+
+    let mut record_data = record_pda.data.borrow_mut();
+    if record_data.len() < LARGE_RECORD_HEADER_LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if &record_data[..LARGE_RECORD_HEADER_LEN] != authority.key.as_ref() {
+        return Err(SnowyError::Unauthorized.into());
+    }
+    if end > record_data.len() {
+        return Err(SnowyError::WriteOutOfBounds.into());
+    }
+
+    record_data[offset..end].copy_from_slice(&data);
+
+    msg!(
+        "SNOWY large record write. authority={}, offset={}, len={}",
+        authority.key,
+        offset,
+        data.len()
+    );
+    Ok(())
+}
+
+fn update(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    new_request_hash: [u8; 32],
+    timestamp: i64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let authority = next_account_info(account_info_iter)?;
+    let record_pda = next_account_info(account_info_iter)?;
+    let instructions_sysvar = next_account_info(account_info_iter)?;
+
+    if !authority.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if *instructions_sysvar.key != ix_sysvar::id() {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if record_pda.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut record = InferenceRecord::try_from_slice(&record_pda.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    if record.authority != *authority.key {
+        return Err(SnowyError::Unauthorized.into());
+    }
+
+    // Require a fresh ed25519 verification over the new hash, same as at creation.
+    if !tx_contains_ed25519_verification(instructions_sysvar, authority.key, &new_request_hash)? {
+        msg!("Missing ed25519 verification for authority+new_request_hash");
+        return Err(SnowyError::MissingEd25519Verification.into());
+    }
+
+    record.request_hash = new_request_hash;
+    record.timestamp = timestamp;
+
+    record
+        .serialize(&mut &mut record_pda.data.borrow_mut()[..])
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    msg!(
+        "SNOWY record updated. authority={}, ts={} request_hash={:?}",
+        authority.key,
+        timestamp,
+        new_request_hash
+    );
+    Ok(())
+}
+
+fn close_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let authority = next_account_info(account_info_iter)?;
+    let record_pda = next_account_info(account_info_iter)?;
+    let destination = next_account_info(account_info_iter)?;
+
+    if !authority.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if record_pda.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let record = InferenceRecord::try_from_slice(&record_pda.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    if record.authority != *authority.key {
+        return Err(SnowyError::Unauthorized.into());
+    }
+
+    let dest_starting_lamports = destination.lamports();
+    **destination.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(record_pda.lamports())
+        .ok_or(ProgramError::InvalidArgument)?;
+    **record_pda.lamports.borrow_mut() = 0;
+
+    // Standard close pattern: zero the data, shrink it, and hand ownership back to
+    // the System Program so the account can't be mistaken for a live record (e.g.
+    // by `write()`'s owner check) if it's ever reused before being garbage
+    // collected for having zero lamports.
+    record_pda.data.borrow_mut().fill(0);
+    record_pda.realloc(0, false)?;
+    record_pda.assign(&solana_program::system_program::id());
+
+    msg!("SNOWY record closed. authority={}", authority.key);
+    Ok(())
+}
+
+fn set_authority(program_id: &Pubkey, accounts: &[AccountInfo], new_authority: Pubkey) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let authority = next_account_info(account_info_iter)?;
+    let record_pda = next_account_info(account_info_iter)?;
+
+    if !authority.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if record_pda.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut record = InferenceRecord::try_from_slice(&record_pda.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    if record.authority != *authority.key {
+        return Err(SnowyError::Unauthorized.into());
+    }
+
+    record.authority = new_authority;
+
+    record
+        .serialize(&mut &mut record_pda.data.borrow_mut()[..])
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    msg!(
+        "SNOWY record authority rotated. old={}, new={}",
+        authority.key,
+        new_authority
+    );
+    Ok(())
+}
+
+#[cfg(feature = "wormhole")]
+mod wormhole {
+    use super::*;
+    use solana_program::instruction::AccountMeta;
+
+    /// Wormhole core bridge program id (mainnet).
+    pub mod bridge {
+        solana_program::declare_id!("worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth");
+    }
+
+    const PAYLOAD_VERSION: u8 = 1;
+
+    /// Fixed-layout payload published to the Wormhole core bridge: version byte,
+    /// 32-byte signer pubkey, 32-byte request hash, 8-byte little-endian timestamp.
+    pub fn encode_payload(signer: &Pubkey, request_hash: &[u8; 32], timestamp: i64) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(1 + 32 + 32 + 8);
+        payload.push(PAYLOAD_VERSION);
+        payload.extend_from_slice(signer.as_ref());
+        payload.extend_from_slice(request_hash);
+        payload.extend_from_slice(&timestamp.to_le_bytes());
+        payload
+    }
+
+    /// Builds the core bridge's `PostMessage` instruction (tag `0x01`) by hand,
+    /// since this program does not otherwise depend on a Wormhole SDK crate.
+    #[allow(clippy::too_many_arguments)]
+    pub fn post_message_instruction(
+        bridge_config: &Pubkey,
+        message: &Pubkey,
+        emitter: &Pubkey,
+        sequence: &Pubkey,
+        payer: &Pubkey,
+        fee_collector: &Pubkey,
+        nonce: u32,
+        payload: Vec<u8>,
+        consistency_level: u8,
+    ) -> Instruction {
+        let mut data = vec![1u8];
+        data.extend_from_slice(&nonce.to_le_bytes());
+        data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        data.extend_from_slice(&payload);
+        data.push(consistency_level);
+
+        Instruction {
+            program_id: bridge::id(),
+            accounts: vec![
+                AccountMeta::new(*bridge_config, false),
+                AccountMeta::new(*message, true),
+                AccountMeta::new_readonly(*emitter, true),
+                AccountMeta::new(*sequence, false),
+                AccountMeta::new(*payer, true),
+                AccountMeta::new(*fee_collector, false),
+                AccountMeta::new_readonly(solana_program::sysvar::clock::id(), false),
+                AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            ],
+            data,
+        }
+    }
+}
+
+#[cfg(feature = "wormhole")]
+fn record_and_bridge(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    request_hash: [u8; 32],
+    timestamp: i64,
+    nonce: u32,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signer = next_account_info(account_info_iter)?;
+    let record_pda = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+    let instructions_sysvar = next_account_info(account_info_iter)?;
+    let wormhole_program = next_account_info(account_info_iter)?;
+    let bridge_config = next_account_info(account_info_iter)?;
+    let wormhole_message = next_account_info(account_info_iter)?;
+    let fee_collector = next_account_info(account_info_iter)?;
+    let sequence = next_account_info(account_info_iter)?;
+    let clock_sysvar = next_account_info(account_info_iter)?;
+
+    if *wormhole_program.key != wormhole::bridge::id() {
+        return Err(SnowyError::IncorrectBridgeProgram.into());
+    }
+
+    record(
+        program_id,
+        &[
+            signer.clone(),
+            record_pda.clone(),
+            system_program.clone(),
+            instructions_sysvar.clone(),
+        ],
+        request_hash,
+        timestamp,
+    )?;
+
+    let (expected_pda, bump) =
+        Pubkey::find_program_address(&[b"snowy", signer.key.as_ref(), &request_hash], program_id);
+    if expected_pda != *record_pda.key {
+        return Err(SnowyError::RecordPdaMismatch.into());
+    }
+
+    let payload = wormhole::encode_payload(signer.key, &request_hash, timestamp);
+    let post_message_ix = wormhole::post_message_instruction(
+        bridge_config.key,
+        wormhole_message.key,
+        record_pda.key,
+        sequence.key,
+        signer.key,
+        fee_collector.key,
+        nonce,
+        payload,
+        0,
+    );
+
+    invoke_signed(
+        &post_message_ix,
+        &[
+            bridge_config.clone(),
+            wormhole_message.clone(),
+            record_pda.clone(),
+            sequence.clone(),
+            signer.clone(),
+            fee_collector.clone(),
+            clock_sysvar.clone(),
+            system_program.clone(),
+            wormhole_program.clone(),
+        ],
+        &[&[b"snowy", signer.key.as_ref(), &request_hash, &[bump]]],
+    )?;
+
+    msg!(
+        "SNOWY record bridged via Wormhole. signer={}, ts={}, nonce={}",
+        signer.key,
+        timestamp,
+        nonce
+    );
+    Ok(())
+}
+
 fn tx_contains_ed25519_verification(
     instructions_sysvar: &AccountInfo,
     signer_pubkey: &Pubkey,
     message32: &[u8; 32],
 ) -> Result<bool, ProgramError> {
+    Ok(count_ed25519_verifications(instructions_sysvar, std::slice::from_ref(signer_pubkey), message32)? >= 1)
+}
+
+/// Scans every Ed25519SigVerify instruction in the transaction and returns how many
+/// distinct pubkeys from `expected_signers` produced a valid 64-byte signature over
+/// `message32`. A pubkey is counted at most once even if it appears in multiple
+/// ed25519 instructions or `expected_signers` contains it more than once.
+fn count_ed25519_verifications(
+    instructions_sysvar: &AccountInfo,
+    expected_signers: &[Pubkey],
+    message32: &[u8; 32],
+) -> Result<usize, ProgramError> {
+    let mut matched: Vec<Pubkey> = Vec::new();
     let mut idx: u16 = 0;
     loop {
         let ix: Instruction = match ix_sysvar::load_instruction_at_checked(idx as usize, instructions_sysvar) {
@@ -157,15 +785,20 @@ fn tx_contains_ed25519_verification(
         };
 
         if ix.program_id == ed25519_program::id() {
-            if ed25519_ix_matches(&ix.data, signer_pubkey, message32)? {
-                return Ok(true);
+            for pubkey in expected_signers {
+                if matched.contains(pubkey) {
+                    continue;
+                }
+                if ed25519_ix_matches(&ix.data, pubkey, message32)? {
+                    matched.push(*pubkey);
+                }
             }
         }
 
         idx = idx.checked_add(1).ok_or(ProgramError::InvalidArgument)?;
     }
 
-    Ok(false)
+    Ok(matched.len())
 }
 
 // Ed25519 instruction layout (Solana built-in ed25519 program):
@@ -242,3 +875,550 @@ solana_program::entrypoint!(entrypoint);
 fn entrypoint(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     process_instruction(program_id, accounts, data)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::instruction::{AccountMeta, InstructionError};
+    use solana_program_test::{processor, ProgramTest};
+    use solana_sdk::{
+        ed25519_instruction::new_ed25519_instruction,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+
+    fn program_test() -> ProgramTest {
+        ProgramTest::new("snowy_sdk", id(), processor!(process_instruction))
+    }
+
+    /// `new_ed25519_instruction` signs with an `ed25519_dalek::Keypair`, not the
+    /// `solana_sdk::signature::Keypair` used to sign transactions; convert between
+    /// the two so a single wallet keypair can do both in a test.
+    fn dalek_keypair(keypair: &Keypair) -> ed25519_dalek::Keypair {
+        ed25519_dalek::Keypair::from_bytes(&keypair.to_bytes()).unwrap()
+    }
+
+    fn record_ix(payer: &Pubkey, record_pda: &Pubkey, request_hash: [u8; 32], timestamp: i64) -> Instruction {
+        Instruction {
+            program_id: id(),
+            accounts: vec![
+                AccountMeta::new(*payer, true),
+                AccountMeta::new(*record_pda, false),
+                AccountMeta::new_readonly(solana_program::system_program::id(), false),
+                AccountMeta::new_readonly(ix_sysvar::id(), false),
+            ],
+            data: SnowyInstruction::Record { request_hash, timestamp }
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+
+    fn update_ix(authority: &Pubkey, record_pda: &Pubkey, new_request_hash: [u8; 32], timestamp: i64) -> Instruction {
+        Instruction {
+            program_id: id(),
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*record_pda, false),
+                AccountMeta::new_readonly(ix_sysvar::id(), false),
+            ],
+            data: SnowyInstruction::Update { new_request_hash, timestamp }
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+
+    fn close_account_ix(authority: &Pubkey, record_pda: &Pubkey, destination: &Pubkey) -> Instruction {
+        Instruction {
+            program_id: id(),
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*record_pda, false),
+                AccountMeta::new(*destination, false),
+            ],
+            data: SnowyInstruction::CloseAccount.try_to_vec().unwrap(),
+        }
+    }
+
+    fn set_authority_ix(authority: &Pubkey, record_pda: &Pubkey, new_authority: Pubkey) -> Instruction {
+        Instruction {
+            program_id: id(),
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*record_pda, false),
+            ],
+            data: SnowyInstruction::SetAuthority { new_authority }.try_to_vec().unwrap(),
+        }
+    }
+
+    fn record_pda(signer: &Pubkey, request_hash: &[u8; 32]) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"snowy", signer.as_ref(), request_hash], &id())
+    }
+
+    fn custom_error_code(err: &TransactionError) -> Option<u32> {
+        match err {
+            TransactionError::InstructionError(_, InstructionError::Custom(code)) => Some(*code),
+            _ => None,
+        }
+    }
+
+    #[tokio::test]
+    async fn update_rotates_hash_with_fresh_ed25519_proof() {
+        let (mut banks_client, payer, recent_blockhash) = program_test().start().await;
+        let signer = Keypair::new();
+        let request_hash = [1u8; 32];
+        let (pda, _) = record_pda(&signer.pubkey(), &request_hash);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                system_instruction::transfer(&payer.pubkey(), &signer.pubkey(), 1_000_000_000),
+                new_ed25519_instruction(&dalek_keypair(&signer), &request_hash),
+                record_ix(&signer.pubkey(), &pda, request_hash, 111),
+            ],
+            Some(&payer.pubkey()),
+            &[&payer, &signer],
+            recent_blockhash,
+        );
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let new_hash = [2u8; 32];
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                new_ed25519_instruction(&dalek_keypair(&signer), &new_hash),
+                update_ix(&signer.pubkey(), &pda, new_hash, 222),
+            ],
+            Some(&payer.pubkey()),
+            &[&payer, &signer],
+            recent_blockhash,
+        );
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let account = banks_client.get_account(pda).await.unwrap().unwrap();
+        let record = InferenceRecord::try_from_slice(&account.data).unwrap();
+        assert_eq!(record.request_hash, new_hash);
+        assert_eq!(record.timestamp, 222);
+    }
+
+    #[tokio::test]
+    async fn update_rejects_non_authority_signer() {
+        let (mut banks_client, payer, recent_blockhash) = program_test().start().await;
+        let signer = Keypair::new();
+        let impostor = Keypair::new();
+        let request_hash = [3u8; 32];
+        let (pda, _) = record_pda(&signer.pubkey(), &request_hash);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                system_instruction::transfer(&payer.pubkey(), &signer.pubkey(), 1_000_000_000),
+                new_ed25519_instruction(&dalek_keypair(&signer), &request_hash),
+                record_ix(&signer.pubkey(), &pda, request_hash, 10),
+            ],
+            Some(&payer.pubkey()),
+            &[&payer, &signer],
+            recent_blockhash,
+        );
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let new_hash = [4u8; 32];
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                new_ed25519_instruction(&dalek_keypair(&impostor), &new_hash),
+                update_ix(&impostor.pubkey(), &pda, new_hash, 20),
+            ],
+            Some(&payer.pubkey()),
+            &[&payer, &impostor],
+            recent_blockhash,
+        );
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_eq!(
+            custom_error_code(&err.unwrap()),
+            Some(SnowyError::Unauthorized as u32)
+        );
+    }
+
+    #[tokio::test]
+    async fn close_account_reclaims_lamports_and_releases_ownership() {
+        let (mut banks_client, payer, recent_blockhash) = program_test().start().await;
+        let signer = Keypair::new();
+        let request_hash = [5u8; 32];
+        let (pda, _) = record_pda(&signer.pubkey(), &request_hash);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                system_instruction::transfer(&payer.pubkey(), &signer.pubkey(), 1_000_000_000),
+                new_ed25519_instruction(&dalek_keypair(&signer), &request_hash),
+                record_ix(&signer.pubkey(), &pda, request_hash, 1),
+            ],
+            Some(&payer.pubkey()),
+            &[&payer, &signer],
+            recent_blockhash,
+        );
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let destination = Keypair::new().pubkey();
+        let tx = Transaction::new_signed_with_payer(
+            &[close_account_ix(&signer.pubkey(), &pda, &destination)],
+            Some(&payer.pubkey()),
+            &[&payer, &signer],
+            recent_blockhash,
+        );
+        banks_client.process_transaction(tx).await.unwrap();
+
+        assert!(banks_client.get_balance(destination).await.unwrap() > 0);
+        let closed = banks_client.get_account(pda).await.unwrap();
+        assert!(closed.map_or(true, |a| a.lamports == 0 && a.owner == solana_program::system_program::id()));
+    }
+
+    #[tokio::test]
+    async fn set_authority_rotates_update_rights() {
+        let (mut banks_client, payer, recent_blockhash) = program_test().start().await;
+        let signer = Keypair::new();
+        let new_authority = Keypair::new();
+        let request_hash = [6u8; 32];
+        let (pda, _) = record_pda(&signer.pubkey(), &request_hash);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                system_instruction::transfer(&payer.pubkey(), &signer.pubkey(), 1_000_000_000),
+                new_ed25519_instruction(&dalek_keypair(&signer), &request_hash),
+                record_ix(&signer.pubkey(), &pda, request_hash, 1),
+            ],
+            Some(&payer.pubkey()),
+            &[&payer, &signer],
+            recent_blockhash,
+        );
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[set_authority_ix(&signer.pubkey(), &pda, new_authority.pubkey())],
+            Some(&payer.pubkey()),
+            &[&payer, &signer],
+            recent_blockhash,
+        );
+        banks_client.process_transaction(tx).await.unwrap();
+
+        // The old authority can no longer update the record.
+        let new_hash = [7u8; 32];
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                new_ed25519_instruction(&dalek_keypair(&signer), &new_hash),
+                update_ix(&signer.pubkey(), &pda, new_hash, 2),
+            ],
+            Some(&payer.pubkey()),
+            &[&payer, &signer],
+            recent_blockhash,
+        );
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_eq!(
+            custom_error_code(&err.unwrap()),
+            Some(SnowyError::Unauthorized as u32)
+        );
+
+        // The new authority can.
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                new_ed25519_instruction(&dalek_keypair(&new_authority), &new_hash),
+                update_ix(&new_authority.pubkey(), &pda, new_hash, 3),
+            ],
+            Some(&payer.pubkey()),
+            &[&payer, &new_authority],
+            recent_blockhash,
+        );
+        banks_client.process_transaction(tx).await.unwrap();
+    }
+
+    fn record_multisig_ix(
+        payer: &Pubkey,
+        record_pda: &Pubkey,
+        request_hash: [u8; 32],
+        timestamp: i64,
+        threshold: u8,
+        signers: Vec<Pubkey>,
+    ) -> Instruction {
+        Instruction {
+            program_id: id(),
+            accounts: vec![
+                AccountMeta::new(*payer, true),
+                AccountMeta::new(*record_pda, false),
+                AccountMeta::new_readonly(solana_program::system_program::id(), false),
+                AccountMeta::new_readonly(ix_sysvar::id(), false),
+            ],
+            data: SnowyInstruction::RecordMultisig {
+                request_hash,
+                timestamp,
+                threshold,
+                signers,
+            }
+            .try_to_vec()
+            .unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn record_multisig_requires_threshold_distinct_signers() {
+        let (mut banks_client, payer, recent_blockhash) = program_test().start().await;
+        let a = Keypair::new();
+        let b = Keypair::new();
+        let c = Keypair::new();
+        let request_hash = [8u8; 32];
+        let threshold = 2u8;
+        let mut signers = vec![a.pubkey(), b.pubkey(), c.pubkey()];
+        signers.sort();
+        let signer_set_hash = solana_program::hash::hashv(&signers.iter().map(Pubkey::as_ref).collect::<Vec<_>>());
+        let (pda, _) = Pubkey::find_program_address(&[b"snowy-multisig", signer_set_hash.as_ref()], &id());
+
+        // Only one of the two required signatures: below threshold, must fail.
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                new_ed25519_instruction(&dalek_keypair(&a), &request_hash),
+                record_multisig_ix(&payer.pubkey(), &pda, request_hash, 1, threshold, signers.clone()),
+            ],
+            Some(&payer.pubkey()),
+            &[&payer],
+            recent_blockhash,
+        );
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_eq!(
+            custom_error_code(&err.unwrap()),
+            Some(SnowyError::MissingEd25519Verification as u32)
+        );
+
+        // Two distinct signers meet the threshold.
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                new_ed25519_instruction(&dalek_keypair(&a), &request_hash),
+                new_ed25519_instruction(&dalek_keypair(&b), &request_hash),
+                record_multisig_ix(&payer.pubkey(), &pda, request_hash, 1, threshold, signers.clone()),
+            ],
+            Some(&payer.pubkey()),
+            &[&payer],
+            recent_blockhash,
+        );
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let account = banks_client.get_account(pda).await.unwrap().unwrap();
+        let record = InferenceRecord::try_from_slice(&account.data).unwrap();
+        assert_eq!(record.threshold, threshold);
+        assert_eq!(record.signers, signers);
+        // No single key can unilaterally mutate a multisig record.
+        assert_eq!(record.authority, pda);
+    }
+
+    #[tokio::test]
+    async fn record_multisig_rejects_duplicate_signers() {
+        let (mut banks_client, payer, recent_blockhash) = program_test().start().await;
+        let a = Keypair::new();
+        let request_hash = [9u8; 32];
+        let signers = vec![a.pubkey(), a.pubkey()];
+        let signer_set_hash = solana_program::hash::hashv(&[a.pubkey().as_ref(), a.pubkey().as_ref()]);
+        let (pda, _) = Pubkey::find_program_address(&[b"snowy-multisig", signer_set_hash.as_ref()], &id());
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                new_ed25519_instruction(&dalek_keypair(&a), &request_hash),
+                record_multisig_ix(&payer.pubkey(), &pda, request_hash, 1, 1, signers),
+            ],
+            Some(&payer.pubkey()),
+            &[&payer],
+            recent_blockhash,
+        );
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_eq!(
+            custom_error_code(&err.unwrap()),
+            Some(SnowyError::DuplicateSigner as u32)
+        );
+    }
+
+    fn initialize_large_ix(signer: &Pubkey, record_pda: &Pubkey, space: u64) -> Instruction {
+        Instruction {
+            program_id: id(),
+            accounts: vec![
+                AccountMeta::new(*signer, true),
+                AccountMeta::new(*record_pda, false),
+                AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            ],
+            data: SnowyInstruction::InitializeLarge { space }.try_to_vec().unwrap(),
+        }
+    }
+
+    fn write_ix(authority: &Pubkey, record_pda: &Pubkey, offset: u64, data: Vec<u8>) -> Instruction {
+        Instruction {
+            program_id: id(),
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*record_pda, false),
+            ],
+            data: SnowyInstruction::Write { offset, data }.try_to_vec().unwrap(),
+        }
+    }
+
+    fn large_record_pda(authority: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"snowy-large", authority.as_ref()], &id())
+    }
+
+    #[tokio::test]
+    async fn initialize_large_then_write_within_bounds_round_trips() {
+        let (mut banks_client, payer, recent_blockhash) = program_test().start().await;
+        let authority = Keypair::new();
+        let (pda, _) = large_record_pda(&authority.pubkey());
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                system_instruction::transfer(&payer.pubkey(), &authority.pubkey(), 1_000_000_000),
+                initialize_large_ix(&authority.pubkey(), &pda, 64),
+            ],
+            Some(&payer.pubkey()),
+            &[&payer, &authority],
+            recent_blockhash,
+        );
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let payload = vec![42u8; 16];
+        let tx = Transaction::new_signed_with_payer(
+            &[write_ix(&authority.pubkey(), &pda, 32, payload.clone())],
+            Some(&payer.pubkey()),
+            &[&payer, &authority],
+            recent_blockhash,
+        );
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let account = banks_client.get_account(pda).await.unwrap().unwrap();
+        assert_eq!(&account.data[32..48], payload.as_slice());
+    }
+
+    #[tokio::test]
+    async fn write_rejects_data_past_allocated_space() {
+        let (mut banks_client, payer, recent_blockhash) = program_test().start().await;
+        let authority = Keypair::new();
+        let (pda, _) = large_record_pda(&authority.pubkey());
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                system_instruction::transfer(&payer.pubkey(), &authority.pubkey(), 1_000_000_000),
+                initialize_large_ix(&authority.pubkey(), &pda, 64),
+            ],
+            Some(&payer.pubkey()),
+            &[&payer, &authority],
+            recent_blockhash,
+        );
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[write_ix(&authority.pubkey(), &pda, 60, vec![1u8; 16])],
+            Some(&payer.pubkey()),
+            &[&payer, &authority],
+            recent_blockhash,
+        );
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_eq!(
+            custom_error_code(&err.unwrap()),
+            Some(SnowyError::WriteOutOfBounds as u32)
+        );
+    }
+
+    #[tokio::test]
+    async fn write_rejects_ordinary_record_pda_masquerading_as_large_record() {
+        // Regression test: a plain `Record`'s first 32 bytes (its `signer` field)
+        // happen to equal its own creator's pubkey, same as a large record's
+        // header. Without re-deriving the `InitializeLarge` PDA, that creator
+        // could `Write` straight over `request_hash`/`timestamp`/`authority`
+        // bypassing `Update`'s ed25519 re-verification entirely.
+        let (mut banks_client, payer, recent_blockhash) = program_test().start().await;
+        let signer = Keypair::new();
+        let request_hash = [10u8; 32];
+        let (pda, _) = record_pda(&signer.pubkey(), &request_hash);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                system_instruction::transfer(&payer.pubkey(), &signer.pubkey(), 1_000_000_000),
+                new_ed25519_instruction(&dalek_keypair(&signer), &request_hash),
+                record_ix(&signer.pubkey(), &pda, request_hash, 1),
+            ],
+            Some(&payer.pubkey()),
+            &[&payer, &signer],
+            recent_blockhash,
+        );
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[write_ix(&signer.pubkey(), &pda, 64, [99u8; 32].to_vec())],
+            Some(&payer.pubkey()),
+            &[&payer, &signer],
+            recent_blockhash,
+        );
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_eq!(
+            custom_error_code(&err.unwrap()),
+            Some(SnowyError::RecordPdaMismatch as u32)
+        );
+    }
+
+    #[cfg(feature = "wormhole")]
+    #[test]
+    fn wormhole_payload_has_stable_fixed_layout() {
+        let signer = Pubkey::new_unique();
+        let request_hash = [11u8; 32];
+        let timestamp = -7i64;
+
+        let payload = wormhole::encode_payload(&signer, &request_hash, timestamp);
+
+        assert_eq!(payload.len(), 1 + 32 + 32 + 8);
+        assert_eq!(payload[0], 1); // version byte
+        assert_eq!(&payload[1..33], signer.as_ref());
+        assert_eq!(&payload[33..65], &request_hash);
+        assert_eq!(&payload[65..73], &timestamp.to_le_bytes());
+    }
+
+    #[cfg(feature = "wormhole")]
+    #[test]
+    fn wormhole_post_message_instruction_targets_bridge_with_expected_accounts() {
+        let bridge_config = Pubkey::new_unique();
+        let message = Pubkey::new_unique();
+        let emitter = Pubkey::new_unique();
+        let sequence = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let fee_collector = Pubkey::new_unique();
+        let payload = vec![1u8, 2, 3];
+
+        let ix = wormhole::post_message_instruction(
+            &bridge_config,
+            &message,
+            &emitter,
+            &sequence,
+            &payer,
+            &fee_collector,
+            42,
+            payload.clone(),
+            1,
+        );
+
+        assert_eq!(ix.program_id, wormhole::bridge::id());
+
+        // Account order/signer/writable flags must match what the core bridge's
+        // PostMessage instruction expects, or every CPI fails at the runtime's
+        // account-metadata check regardless of what invoke_signed() passes.
+        let expected = [
+            (bridge_config, false, true),
+            (message, true, true),
+            (emitter, true, false),
+            (sequence, false, true),
+            (payer, true, true),
+            (fee_collector, false, true),
+            (solana_program::sysvar::clock::id(), false, false),
+            (solana_program::system_program::id(), false, false),
+        ];
+        assert_eq!(ix.accounts.len(), expected.len());
+        for (meta, (key, is_signer, is_writable)) in ix.accounts.iter().zip(expected.iter()) {
+            assert_eq!(meta.pubkey, *key);
+            assert_eq!(meta.is_signer, *is_signer);
+            assert_eq!(meta.is_writable, *is_writable);
+        }
+
+        // Instruction data: tag(1) + nonce(4) + payload_len(4) + payload + consistency_level(1).
+        let mut expected_data = vec![1u8];
+        expected_data.extend_from_slice(&42u32.to_le_bytes());
+        expected_data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        expected_data.extend_from_slice(&payload);
+        expected_data.push(1);
+        assert_eq!(ix.data, expected_data);
+    }
+}